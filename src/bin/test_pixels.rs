@@ -1,18 +1,27 @@
 #![deny(clippy::all)]
 #![forbid(unsafe_code)]
 
+// Building for the browser: compile with `--target wasm32-unknown-unknown`.
+// `pixels` exposes no features of its own; the WebGL-2 backend is turned on by
+// depending on the same wgpu version as `pixels` with its `webgl` feature, so
+// Cargo feature-unification enables it on the wgpu the renderer actually uses
+// (see the wasm32 `wgpu` dependency in Cargo.toml).
+
 use log::error;
-use pixels::{Error, Pixels, SurfaceTexture};
+use pixels::{Error, PixelsBuilder, SurfaceTexture};
 use winit::dpi::LogicalSize;
 use winit::event::{Event, VirtualKeyCode};
 use winit::event_loop::{ControlFlow, EventLoop};
 use winit::window::WindowBuilder;
 use winit_input_helper::WinitInputHelper;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
 use smatrix::user_type::camera::Camera;
 use smatrix::user_type::object_buffer::{ObjectBuffer, Triangle};
 use smatrix::user_type::position::Pos3;
 use smatrix::user_type::matrix::Matrix;
 use smatrix::user_type::vector::Vector3;
+use smatrix::user_type::viewport::{fit_to_window, ScaledRegion};
 
 
 const WIDTH: u32 = 320;
@@ -26,12 +35,40 @@ struct World {
     velocity_x: i16,
     velocity_y: i16,
     camera: Camera,
-    buffer: ObjectBuffer,
     theta: f32,
+    /// Aspect-preserving destination rectangle for the render inside the window.
+    region: ScaledRegion,
+    /// Current window (pixel buffer) dimensions.
+    win_w: u32,
+    win_h: u32,
 }
 
+/// Native entry point: set up logging and drive the async `run` to completion.
+#[cfg(not(target_arch = "wasm32"))]
 fn main() -> Result<(), Error> {
     env_logger::init();
+    pollster::block_on(run())
+}
+
+/// `main` is unused on wasm; the real entry point is [`start`].
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+/// WebAssembly entry point, exported to JavaScript. Installs the panic hook and
+/// console logger, then spawns the async `run` on the browser's event loop.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(start)]
+pub fn start() {
+    console_error_panic_hook::set_once();
+    console_log::init_with_level(log::Level::Info).expect("could not initialize logger");
+    wasm_bindgen_futures::spawn_local(async {
+        run().await.expect("failed to run event loop");
+    });
+}
+
+/// Build the window and renderer and run the event loop. Async so the same code
+/// path works on the web, where surface creation must be awaited.
+async fn run() -> Result<(), Error> {
     let event_loop = EventLoop::new();
     let mut input = WinitInputHelper::new();
     let window = {
@@ -44,17 +81,40 @@ fn main() -> Result<(), Error> {
             .unwrap()
     };
 
+    // On the web, attach the winit canvas to the document body so the demo
+    // shows up on the page.
+    #[cfg(target_arch = "wasm32")]
+    {
+        use winit::platform::web::WindowExtWebSys;
+        web_sys::window()
+            .and_then(|win| win.document())
+            .and_then(|doc| doc.body())
+            .and_then(|body| body.append_child(&window.canvas()).ok())
+            .expect("could not append canvas to document body");
+    }
+
+    // The pixel buffer tracks the window 1:1 so the software renderer owns the
+    // scaling and can letterbox/pillarbox the fixed WIDTH x HEIGHT image itself
+    // instead of letting the surface stretch it.
+    // On the web the canvas is often reported as 0x0 until it is laid out;
+    // clamp to at least one pixel so surface creation succeeds, then let the
+    // first resize event size it for real.
+    let window_size = window.inner_size();
+    let buf_w = window_size.width.max(1);
+    let buf_h = window_size.height.max(1);
     let mut pixels = {
-        let window_size = window.inner_size();
-        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-        Pixels::new(WIDTH, HEIGHT, surface_texture)?
+        let surface_texture = SurfaceTexture::new(buf_w, buf_h, &window);
+        PixelsBuilder::new(buf_w, buf_h, surface_texture)
+            .build_async()
+            .await?
     };
     let mut world = World::new();
+    world.resize(buf_w, buf_h);
 
     event_loop.run(move |event, _, control_flow| {
         // Draw the current frame
         if let Event::RedrawRequested(_) = event {
-            world.draw(pixels.get_frame_mut());
+            world.draw(pixels.frame_mut());
             if pixels
                 .render()
                 .map_err(|e| error!("pixels.render() failed: {}", e))
@@ -68,18 +128,30 @@ fn main() -> Result<(), Error> {
         // Handle input events
         if input.update(&event) {
             // Close events
-            if input.key_pressed(VirtualKeyCode::Escape) || input.quit() {
+            if input.key_pressed(VirtualKeyCode::Escape) || input.close_requested() {
                 *control_flow = ControlFlow::Exit;
                 return;
             }
 
-            // Resize the window
+            // Resize the window, keeping the buffer's aspect ratio with
+            // letterbox/pillarbox margins instead of stretching it.
             if let Some(size) = input.window_resized() {
-                pixels.resize_surface(size.width, size.height);
+                // A zero dimension (e.g. a minimized window) can't be resized
+                // to and would error; just skip it until the window is shown
+                // again.
+                if size.width > 0 && size.height > 0 {
+                    if pixels.resize_surface(size.width, size.height).is_err()
+                        || pixels.resize_buffer(size.width, size.height).is_err()
+                    {
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    world.resize(size.width, size.height);
+                }
             }
 
             // Update internal state and request a redraw
-            world.update();
+            world.update(&input);
             window.request_redraw();
         }
     });
@@ -88,26 +160,31 @@ fn main() -> Result<(), Error> {
 impl World {
     /// Create a new `World` instance that can draw a moving box.
     fn new() -> Self {
-        let mut _buffer = ObjectBuffer::new();
-        _buffer.add_object(Triangle::new(
-                Pos3::new(1., 2., -9.5),
-                Pos3::new(2., 2.5, -7.5),
-                Pos3::new(1.9, -2., -5.5),
-                ));
-
         Self {
             box_x: 24,
             box_y: 16,
             velocity_x: 1,
             velocity_y: 1,
             camera: Camera::new(10., 1., -5., -10.),
-            buffer: _buffer,
             theta: 0.,
+            region: ScaledRegion { x: 0, y: 0, width: WIDTH, height: HEIGHT },
+            win_w: WIDTH,
+            win_h: HEIGHT,
         }
     }
 
-    /// Update the `World` internal state; bounce the box around the screen.
-    fn update(&mut self) {
+    /// Recompute the aspect-preserving blit region for a new window size.
+    fn resize(&mut self, win_w: u32, win_h: u32) {
+        self.win_w = win_w;
+        self.win_h = win_h;
+        self.region = fit_to_window(WIDTH, HEIGHT, win_w, win_h);
+    }
+
+    /// Update the `World` internal state; bounce the box around the screen and
+    /// let the camera controller fly around in response to input.
+    fn update(&mut self, input: &WinitInputHelper) {
+        self.camera.update(input);
+
         if self.box_x <= 0 || self.box_x + BOX_SIZE > WIDTH as i16 {
             self.velocity_x *= -1;
         }
@@ -132,14 +209,47 @@ impl World {
         let _mat = Vector3::new(0., 1., 0.).to_rotation_matrix(self.theta);
         let _move_back = Matrix::move_matrix(2., 2.5, -7.5);
         let _mat = ((&_move_back * &_mat).unwrap() * _move_origin).unwrap();
+        // Fold the camera's view transform in front of the model rotation so
+        // the scene reacts to the controller before projection.
+        let _mat = (&self.camera.view_matrix() * &_mat).unwrap();
 
         let p1 = Pos3::from_matrix(&(&_mat * &p1.to_matrix()).unwrap());
         let p2 = Pos3::from_matrix(&(&_mat * &p2.to_matrix()).unwrap());
         let p3 = Pos3::from_matrix(&(&_mat * &p3.to_matrix()).unwrap());
-        println!("theta:{:?}, p:{:?}, {:?}, {:?}", self.theta, p1, p2, p3);
         buffer.add_object(Triangle::new(p1, p2, p3));
         let _buf = self.camera.render(WIDTH, HEIGHT, &buffer);
 
-        frame.copy_from_slice(&_buf.display);
+        self.blit(&_buf.display, frame);
+    }
+
+    /// Scale the fixed WIDTH x HEIGHT `src` image into the window-sized `frame`
+    /// at the current aspect-preserving region, filling the letterbox/pillarbox
+    /// margins with opaque black. Nearest-neighbour keeps pixels square.
+    fn blit(&self, src: &[u8], frame: &mut [u8]) {
+        let region = self.region;
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        // When there are letterbox/pillarbox margins, clear the whole frame to
+        // black; the region itself is then overwritten below. When it covers
+        // the whole frame there are no margins and nothing to clear.
+        if region.width < self.win_w || region.height < self.win_h {
+            for px in frame.chunks_exact_mut(4) {
+                px[0] = 0;
+                px[1] = 0;
+                px[2] = 0;
+                px[3] = 0xff;
+            }
+        }
+        for ry in 0..region.height {
+            let sy = ry * HEIGHT / region.height;
+            for rx in 0..region.width {
+                let sx = rx * WIDTH / region.width;
+                let s = ((sy * WIDTH + sx) * 4) as usize;
+                let d = (((region.y + ry) * self.win_w + region.x + rx) * 4) as usize;
+                frame[d..d + 4].copy_from_slice(&src[s..s + 4]);
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,338 @@
+use winit::event::VirtualKeyCode;
+use winit_input_helper::WinitInputHelper;
+
+use super::matrix::Matrix;
+use super::object_buffer::ObjectBuffer;
+use super::position::Pos3;
+use super::vector::Vector3;
+
+/// Eye translation per update, in world units.
+const MOVE_SPEED: f32 = 0.1;
+/// Look rotation per update from the keyboard, in radians.
+const LOOK_SPEED: f32 = 0.03;
+/// Scales raw mouse motion into a look rotation, in radians per pixel.
+const MOUSE_SENSITIVITY: f32 = 0.003;
+/// `WinitInputHelper`'s button index for the right mouse button.
+const RIGHT_MOUSE_BUTTON: usize = 1;
+/// Constant ambient term so faces turned away from the light aren't pure black.
+const AMBIENT: f32 = 0.2;
+/// Top-left fill-rule bias, as a fraction of a pixel of perpendicular distance.
+/// It is scaled by each edge's length below so the effective threshold is this
+/// many pixels regardless of edge size: a fragment on a non-top-left edge must
+/// clear it to count as inside, so a shared edge is filled by exactly one of the
+/// two adjacent triangles. Kept well under a pixel so no interior fragment is
+/// lost even for sub-pixel triangles.
+const EDGE_BIAS: f32 = 1. / 32.;
+
+/// The result of a single software-rendered frame.
+///
+/// `display` is a tightly packed `width * height` RGBA8 buffer ready to hand to
+/// `pixels`, and `depth` is the matching z-buffer, one `f32` per pixel, holding
+/// the camera-space distance of the nearest fragment written so far.
+pub struct RenderOutput {
+    pub display: Vec<u8>,
+    pub depth: Vec<f32>,
+}
+
+/// A pinhole camera that projects and rasterizes an [`ObjectBuffer`] into an
+/// RGBA frame.
+pub struct Camera {
+    pub focal: f32,
+    pub aspect: f32,
+    pub near: f32,
+    pub far: f32,
+    /// Eye position in world space.
+    pub eye: Pos3,
+    /// Rotation about the world up axis, in radians.
+    pub yaw: f32,
+    /// Rotation about the camera's right axis, in radians.
+    pub pitch: f32,
+    /// Direction the directional light travels in, in the same space as the
+    /// triangles handed to [`Camera::render`].
+    pub light_dir: Vector3,
+}
+
+impl Camera {
+    pub fn new(focal: f32, aspect: f32, near: f32, far: f32) -> Self {
+        Self {
+            focal,
+            aspect,
+            near,
+            far,
+            eye: Pos3::new(0., 0., 0.),
+            yaw: 0.,
+            pitch: 0.,
+            light_dir: Vector3::new(0., -1., -1.).normalize(),
+        }
+    }
+
+    /// The camera's forward axis, derived from the current yaw and pitch.
+    /// With both zero it points down `-Z`, matching the default scene.
+    fn forward(&self) -> Vector3 {
+        Vector3::new(
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            -self.pitch.cos() * self.yaw.cos(),
+        )
+    }
+
+    /// The camera's right axis in the ground plane.
+    fn right(&self) -> Vector3 {
+        Vector3::new(self.yaw.cos(), 0., self.yaw.sin())
+    }
+
+    /// Advance the controller from one frame of input.
+    ///
+    /// WASD translate the eye along its local forward/right axes, the arrow
+    /// keys yaw and pitch, and holding the right mouse button steers with the
+    /// mouse delta. Intended to be called once per `World::update`.
+    pub fn update(&mut self, input: &WinitInputHelper) {
+        let forward = self.forward();
+        let right = self.right();
+
+        let mut step = |dir: Vector3, amount: f32| {
+            self.eye.x += dir.x * amount;
+            self.eye.y += dir.y * amount;
+            self.eye.z += dir.z * amount;
+        };
+        if input.key_held(VirtualKeyCode::W) {
+            step(forward, MOVE_SPEED);
+        }
+        if input.key_held(VirtualKeyCode::S) {
+            step(forward, -MOVE_SPEED);
+        }
+        if input.key_held(VirtualKeyCode::D) {
+            step(right, MOVE_SPEED);
+        }
+        if input.key_held(VirtualKeyCode::A) {
+            step(right, -MOVE_SPEED);
+        }
+
+        if input.key_held(VirtualKeyCode::Left) {
+            self.yaw -= LOOK_SPEED;
+        }
+        if input.key_held(VirtualKeyCode::Right) {
+            self.yaw += LOOK_SPEED;
+        }
+        if input.key_held(VirtualKeyCode::Up) {
+            self.pitch += LOOK_SPEED;
+        }
+        if input.key_held(VirtualKeyCode::Down) {
+            self.pitch -= LOOK_SPEED;
+        }
+
+        // Mouse-look only while the right button is held, so ordinary cursor
+        // motion over the window doesn't steer the camera.
+        if input.mouse_held(RIGHT_MOUSE_BUTTON) {
+            let (dx, dy) = input.mouse_diff();
+            self.yaw += dx * MOUSE_SENSITIVITY;
+            self.pitch -= dy * MOUSE_SENSITIVITY;
+        }
+
+        // Keep the camera from flipping over at the poles.
+        let limit = std::f32::consts::FRAC_PI_2 - 0.01;
+        self.pitch = self.pitch.clamp(-limit, limit);
+    }
+
+    /// The view matrix transforming world-space points into camera space:
+    /// undo the eye translation, then the yaw and pitch orientation.
+    pub fn view_matrix(&self) -> Matrix {
+        let translate = Matrix::move_matrix(-self.eye.x, -self.eye.y, -self.eye.z);
+        let un_yaw = Vector3::new(0., 1., 0.).to_rotation_matrix(self.yaw);
+        let un_pitch = Vector3::new(1., 0., 0.).to_rotation_matrix(-self.pitch);
+        let rotate = (&un_pitch * &un_yaw).unwrap();
+        (&rotate * &translate).unwrap()
+    }
+
+    /// Project a camera-space point (eye at the origin looking down `-Z`; no
+    /// view transform is applied here) onto the screen.
+    ///
+    /// Returns the pixel coordinates together with the positive camera-space
+    /// depth (distance in front of the eye), or `None` when the point is on or
+    /// behind the eye's plane (`z >= 0`) and cannot be projected.
+    fn project(&self, p: &Pos3, width: u32, height: u32) -> Option<(f32, f32, f32)> {
+        // The scene looks down -Z, so visible geometry has a negative z.
+        if p.z >= 0. {
+            return None;
+        }
+        let inv = -1. / p.z;
+        let sx = p.x * self.focal * self.aspect * inv + width as f32 / 2.;
+        let sy = -p.y * self.focal * inv + height as f32 / 2.;
+        Some((sx, sy, -p.z))
+    }
+
+    /// Rasterize every triangle in `buffer` into a fresh [`RenderOutput`].
+    ///
+    /// The color buffer is cleared to opaque black and the depth buffer to
+    /// `f32::INFINITY`; each fragment is kept only when it is nearer than the
+    /// depth already recorded for that pixel (the z-buffer test).
+    pub fn render(&self, width: u32, height: u32, buffer: &ObjectBuffer) -> RenderOutput {
+        let pixels = (width * height) as usize;
+        let mut display = vec![0u8; pixels * 4];
+        for px in display.chunks_exact_mut(4) {
+            px[3] = 0xff;
+        }
+        let mut depth = vec![f32::INFINITY; pixels];
+
+        for tri in &buffer.triangles {
+            let (v0, v1, v2) = match (
+                self.project(&tri.p0, width, height),
+                self.project(&tri.p1, width, height),
+                self.project(&tri.p2, width, height),
+            ) {
+                (Some(a), Some(b), Some(c)) => (a, b, c),
+                _ => continue,
+            };
+
+            // Screen-space bounding box, clamped to the frame.
+            let min_x = v0.0.min(v1.0).min(v2.0).floor().max(0.) as u32;
+            let max_x = v0.0.max(v1.0).max(v2.0).ceil().min(width as f32) as u32;
+            let min_y = v0.1.min(v1.1).min(v2.1).floor().max(0.) as u32;
+            let max_y = v0.1.max(v1.1).max(v2.1).ceil().min(height as f32) as u32;
+
+            // Orient the triangle counter-clockwise so the edge functions are
+            // positive inside; skip degenerate (zero-area) triangles.
+            let mut area = edge(v0, v1, (v2.0, v2.1));
+            let (a, b, c) = if area < 0. {
+                area = -area;
+                (v0, v2, v1)
+            } else {
+                (v0, v1, v2)
+            };
+            if area == 0. {
+                continue;
+            }
+
+            // Flat Lambertian term for the whole face: ambient plus diffuse
+            // from the directional light, clamped to the front hemisphere.
+            let normal = tri.normal();
+            // max(0, dot(normal, -light_dir)): brightest when the face points
+            // back toward where the light comes from.
+            let diffuse = (-normal.dot(&self.light_dir)).max(0.);
+            let intensity = (AMBIENT + (1. - AMBIENT) * diffuse).clamp(0., 1.);
+            let shade = (intensity * 255.) as u8;
+
+            // Top-left fill rule: a pixel lying exactly on an edge is drawn only
+            // for edges that are "top" or "left", so shared edges between
+            // adjacent triangles are rasterized by exactly one of them.
+            let bias0 = if is_top_left(b, c) { 0. } else { -EDGE_BIAS * edge_len(b, c) };
+            let bias1 = if is_top_left(c, a) { 0. } else { -EDGE_BIAS * edge_len(c, a) };
+            let bias2 = if is_top_left(a, b) { 0. } else { -EDGE_BIAS * edge_len(a, b) };
+
+            for y in min_y..max_y {
+                for x in min_x..max_x {
+                    let p = (x as f32 + 0.5, y as f32 + 0.5);
+                    let e0 = edge(b, c, p);
+                    let e1 = edge(c, a, p);
+                    let e2 = edge(a, b, p);
+                    if e0 + bias0 < 0. || e1 + bias1 < 0. || e2 + bias2 < 0. {
+                        continue;
+                    }
+
+                    // Barycentric weights feed every interpolated attribute.
+                    let w0 = e0 / area;
+                    let w1 = e1 / area;
+                    let w2 = e2 / area;
+                    let frag_depth = w0 * a.2 + w1 * b.2 + w2 * c.2;
+                    let idx = (y * width + x) as usize;
+                    if frag_depth < depth[idx] {
+                        depth[idx] = frag_depth;
+                        let o = idx * 4;
+                        display[o] = shade;
+                        display[o + 1] = shade;
+                        display[o + 2] = shade;
+                        display[o + 3] = 0xff;
+                    }
+                }
+            }
+        }
+
+        RenderOutput { display, depth }
+    }
+}
+
+/// Edge function `E(a, b, p) = (b.x-a.x)*(p.y-a.y) - (b.y-a.y)*(p.x-a.x)`.
+///
+/// Its sign tells which side of the directed edge `a -> b` the point `p` lies
+/// on, and for the three edges of a triangle the values are proportional to the
+/// barycentric weights.
+fn edge(a: (f32, f32, f32), b: (f32, f32, f32), p: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+/// Screen-space length of the edge `a -> b`, used to turn [`EDGE_BIAS`] into a
+/// perpendicular distance in pixels (one pixel of distance shifts the edge
+/// function by the edge length).
+fn edge_len(a: (f32, f32, f32), b: (f32, f32, f32)) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+/// Whether the directed edge `a -> b` is a top or left edge of a
+/// counter-clockwise triangle, used by the top-left fill rule.
+fn is_top_left(a: (f32, f32, f32), b: (f32, f32, f32)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    // With y pointing down the screen, a left edge goes upward (y decreases)
+    // and a top edge is horizontal, going right.
+    dy < 0. || (dy == 0. && dx > 0.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_type::object_buffer::{ObjectBuffer, Triangle};
+    use crate::user_type::position::Pos3;
+    use std::collections::HashSet;
+
+    const W: u32 = 320;
+    const H: u32 = 240;
+
+    /// The set of pixel indices a render touched, taken from the depth buffer.
+    fn covered(out: &RenderOutput) -> HashSet<usize> {
+        out.depth
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.is_finite())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn render_one(cam: &Camera, tri: Triangle) -> HashSet<usize> {
+        let mut buf = ObjectBuffer::new();
+        buf.add_object(tri);
+        covered(&cam.render(W, H, &buf))
+    }
+
+    // Two triangles that share the diagonal of a screen-facing quad must tile
+    // it without overlap (no double-draw) and without holes (no gap): the
+    // top-left fill rule assigns every shared-edge pixel to exactly one of them.
+    #[test]
+    fn shared_edge_drawn_exactly_once() {
+        let cam = Camera::new(100., 1., -0.1, -100.);
+        let p0 = Pos3::new(-1., 1., -2.);
+        let p1 = Pos3::new(1., 1., -2.);
+        let p2 = Pos3::new(1., -1., -2.);
+        let p3 = Pos3::new(-1., -1., -2.);
+
+        let tri_a = Triangle::new(p0, p1, p2);
+        let tri_b = Triangle::new(p0, p2, p3);
+
+        let cov_a = render_one(&cam, tri_a);
+        let cov_b = render_one(&cam, tri_b);
+
+        // No pixel belongs to both triangles.
+        assert!(cov_a.is_disjoint(&cov_b), "shared edge double-drawn");
+
+        // Their union matches rendering the two triangles together, and it is
+        // every pixel of the quad (which spans 100x100 pixels on screen), so
+        // there is no gap along the shared edge.
+        let mut both = ObjectBuffer::new();
+        both.add_object(tri_a);
+        both.add_object(tri_b);
+        let cov_both = covered(&cam.render(W, H, &both));
+
+        let union: HashSet<usize> = cov_a.union(&cov_b).copied().collect();
+        assert_eq!(union, cov_both);
+        assert!(!cov_both.is_empty());
+        assert_eq!(cov_both.len(), 100 * 100);
+    }
+}
@@ -0,0 +1,88 @@
+use std::ops::Mul;
+
+/// Error returned by matrix operations that can fail at runtime, chiefly a
+/// dimension mismatch during multiplication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The left-hand column count did not match the right-hand row count.
+    DimMismatch,
+}
+
+/// A dense, row-major `rows * cols` matrix of `f32`.
+///
+/// The renderer only ever deals with small matrices (4x4 transforms and 4x1
+/// homogeneous points), so a flat `Vec` is more than fast enough and keeps the
+/// arithmetic easy to read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<f32>,
+}
+
+impl Matrix {
+    /// Build a matrix from its dimensions and row-major data.
+    pub fn new(rows: usize, cols: usize, data: Vec<f32>) -> Self {
+        assert_eq!(rows * cols, data.len(), "matrix data does not fit dimensions");
+        Self { rows, cols, data }
+    }
+
+    /// A `size * size` identity matrix.
+    pub fn identity(size: usize) -> Self {
+        let mut data = vec![0.; size * size];
+        for i in 0..size {
+            data[i * size + i] = 1.;
+        }
+        Self { rows: size, cols: size, data }
+    }
+
+    /// The 4x4 homogeneous translation matrix for the offset `(x, y, z)`.
+    pub fn move_matrix(x: f32, y: f32, z: f32) -> Self {
+        let mut m = Self::identity(4);
+        m.data[3] = x;
+        m.data[7] = y;
+        m.data[11] = z;
+        m
+    }
+
+    /// Read the element at `(row, col)`.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        self.data[row * self.cols + col]
+    }
+
+    /// Multiply `self * rhs`, returning [`MatrixError::DimMismatch`] when the
+    /// inner dimensions disagree.
+    pub fn multiply(&self, rhs: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimMismatch);
+        }
+        let mut data = vec![0.; self.rows * rhs.cols];
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut acc = 0.;
+                for k in 0..self.cols {
+                    acc += self.get(i, k) * rhs.get(k, j);
+                }
+                data[i * rhs.cols + j] = acc;
+            }
+        }
+        Ok(Matrix { rows: self.rows, cols: rhs.cols, data })
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        self.multiply(rhs)
+    }
+}
+
+impl Mul<Matrix> for Matrix {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        self.multiply(&rhs)
+    }
+}
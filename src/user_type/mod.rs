@@ -0,0 +1,6 @@
+pub mod camera;
+pub mod matrix;
+pub mod object_buffer;
+pub mod position;
+pub mod vector;
+pub mod viewport;
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use super::position::Pos3;
+use super::vector::Vector3;
+
+/// A single triangle, stored as its three vertices in object/world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub p0: Pos3,
+    pub p1: Pos3,
+    pub p2: Pos3,
+}
+
+impl Triangle {
+    pub fn new(p0: Pos3, p1: Pos3, p2: Pos3) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    /// The unit geometric normal, as the normalized cross product of the
+    /// `p0->p1` and `p0->p2` edge vectors.
+    pub fn normal(&self) -> Vector3 {
+        let e1 = Vector3::new(
+            self.p1.x - self.p0.x,
+            self.p1.y - self.p0.y,
+            self.p1.z - self.p0.z,
+        );
+        let e2 = Vector3::new(
+            self.p2.x - self.p0.x,
+            self.p2.y - self.p0.y,
+            self.p2.z - self.p0.z,
+        );
+        e1.cross(&e2).normalize()
+    }
+}
+
+/// The scene: a flat list of triangles handed to [`Camera::render`].
+///
+/// [`Camera::render`]: super::camera::Camera::render
+#[derive(Debug, Clone, Default)]
+pub struct ObjectBuffer {
+    pub triangles: Vec<Triangle>,
+}
+
+impl ObjectBuffer {
+    pub fn new() -> Self {
+        Self { triangles: Vec::new() }
+    }
+
+    /// Append a triangle to the scene.
+    pub fn add_object(&mut self, triangle: Triangle) {
+        self.triangles.push(triangle);
+    }
+
+    /// Load a Wavefront `.obj` file into an `ObjectBuffer`.
+    ///
+    /// `v x y z` lines become vertices and `f` lines become triangles; faces
+    /// with more than three vertices are triangulated with a simple fan.
+    /// Face indices may be 1-based (the `.obj` convention) or negative
+    /// (relative to the end of the vertex list), and the usual `v/vt/vn`
+    /// slash syntax is accepted, with only the position index used.
+    pub fn from_obj<P: AsRef<Path>>(path: P) -> Result<Self, ObjError> {
+        let text = fs::read_to_string(path).map_err(ObjError::Io)?;
+        let mut vertices: Vec<Pos3> = Vec::new();
+        let mut buffer = ObjectBuffer::new();
+
+        for (lineno, line) in text.lines().enumerate() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let coords: Vec<f32> = tokens
+                        .take(3)
+                        .map(|t| t.parse::<f32>().map_err(|_| ObjError::Parse(lineno + 1)))
+                        .collect::<Result<_, _>>()?;
+                    if coords.len() != 3 {
+                        return Err(ObjError::Parse(lineno + 1));
+                    }
+                    vertices.push(Pos3::new(coords[0], coords[1], coords[2]));
+                }
+                Some("f") => {
+                    let mut face = Vec::new();
+                    for tok in tokens {
+                        // Keep only the vertex position index of `v/vt/vn`.
+                        let raw = tok.split('/').next().unwrap_or("");
+                        let idx: i32 = raw.parse().map_err(|_| ObjError::Parse(lineno + 1))?;
+                        let resolved = resolve_index(idx, vertices.len())
+                            .ok_or(ObjError::Parse(lineno + 1))?;
+                        face.push(*vertices.get(resolved).ok_or(ObjError::Parse(lineno + 1))?);
+                    }
+                    // Fan triangulation: (0, i, i+1) for every triangle.
+                    for i in 1..face.len().saturating_sub(1) {
+                        buffer.add_object(Triangle::new(face[0], face[i], face[i + 1]));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(buffer)
+    }
+}
+
+/// Resolve a 1-based or negative `.obj` index into a 0-based slot.
+fn resolve_index(idx: i32, len: usize) -> Option<usize> {
+    if idx > 0 {
+        Some((idx - 1) as usize)
+    } else if idx < 0 {
+        len.checked_sub((-idx) as usize)
+    } else {
+        None
+    }
+}
+
+/// Error returned by [`ObjectBuffer::from_obj`].
+#[derive(Debug)]
+pub enum ObjError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// A line could not be parsed; the payload is the 1-based line number.
+    Parse(usize),
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "failed to read obj file: {}", e),
+            ObjError::Parse(line) => write!(f, "malformed obj data on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// Write `contents` to a uniquely named temp file and return its path.
+    fn write_temp(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("{}-{}.obj", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn resolves_positive_and_negative_indices() {
+        assert_eq!(resolve_index(1, 4), Some(0));
+        assert_eq!(resolve_index(4, 4), Some(3));
+        assert_eq!(resolve_index(-1, 4), Some(3));
+        assert_eq!(resolve_index(-4, 4), Some(0));
+        // Zero is invalid and an out-of-range negative wraps to nothing.
+        assert_eq!(resolve_index(0, 4), None);
+        assert_eq!(resolve_index(-5, 4), None);
+    }
+
+    #[test]
+    fn loads_multi_face_obj() {
+        let path = write_temp(
+            "multi",
+            "# a small quad plus some triangles\n\
+             v 0 0 0\n\
+             v 1 0 0\n\
+             v 1 1 0\n\
+             v 0 1 0\n\
+             f 1 2 3\n\
+             f 1/1 2/2 3/3 4/4\n\
+             f 1 2 -1\n",
+        );
+        let buffer = ObjectBuffer::from_obj(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        // 1 triangle + fan-triangulated quad (2) + 1 triangle with a negative
+        // index = 4 triangles.
+        assert_eq!(buffer.triangles.len(), 4);
+        // First face keeps its 1-based vertices in order.
+        assert_eq!(buffer.triangles[0].p0, Pos3::new(0., 0., 0.));
+        assert_eq!(buffer.triangles[0].p2, Pos3::new(1., 1., 0.));
+        // The `f 1 2 -1` face's -1 resolves to the last vertex, v4.
+        assert_eq!(buffer.triangles[3].p2, Pos3::new(0., 1., 0.));
+    }
+
+    #[test]
+    fn reports_parse_error_with_line_number() {
+        let path = write_temp("bad", "v 0 0 0\nv 1 oops 0\n");
+        let err = ObjectBuffer::from_obj(&path).unwrap_err();
+        fs::remove_file(&path).ok();
+        match err {
+            ObjError::Parse(line) => assert_eq!(line, 2),
+            other => panic!("expected Parse error, got {:?}", other),
+        }
+    }
+}
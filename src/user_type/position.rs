@@ -0,0 +1,31 @@
+use super::matrix::Matrix;
+
+/// A point in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pos3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Pos3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Promote the point to a 4x1 homogeneous column vector `[x, y, z, 1]`.
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new(4, 1, vec![self.x, self.y, self.z, 1.])
+    }
+
+    /// Recover a point from a 4x1 homogeneous column vector, applying the
+    /// perspective divide by `w` when it is not already one.
+    pub fn from_matrix(m: &Matrix) -> Self {
+        let w = m.data[3];
+        if w != 0. && w != 1. {
+            Self::new(m.data[0] / w, m.data[1] / w, m.data[2] / w)
+        } else {
+            Self::new(m.data[0], m.data[1], m.data[2])
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use super::matrix::Matrix;
+
+/// A 3D vector, used for directions (axes, normals, light directions).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Euclidean length of the vector.
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// A unit-length copy of the vector; a zero vector is returned unchanged.
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        if len == 0. {
+            *self
+        } else {
+            Self::new(self.x / len, self.y / len, self.z / len)
+        }
+    }
+
+    /// Dot product with `other`.
+    pub fn dot(&self, other: &Vector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product `self x other`.
+    pub fn cross(&self, other: &Vector3) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Build the 4x4 rotation matrix of `theta` radians about this vector as
+    /// the axis, via the Rodrigues formula. The axis is normalized first.
+    pub fn to_rotation_matrix(&self, theta: f32) -> Matrix {
+        let axis = self.normalize();
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+        let c = theta.cos();
+        let s = theta.sin();
+        let t = 1. - c;
+
+        Matrix::new(
+            4,
+            4,
+            vec![
+                t * x * x + c,     t * x * y - s * z, t * x * z + s * y, 0.,
+                t * x * y + s * z, t * y * y + c,     t * y * z - s * x, 0.,
+                t * x * z - s * y, t * y * z + s * x, t * z * z + c,     0.,
+                0.,                0.,                0.,                1.,
+            ],
+        )
+    }
+}
@@ -0,0 +1,34 @@
+/// A pixel-exact rectangle inside the window where the render buffer should be
+/// blitted, with letterbox/pillarbox margins filling the rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaledRegion {
+    /// Left margin, in window pixels.
+    pub x: u32,
+    /// Top margin, in window pixels.
+    pub y: u32,
+    /// Width of the blitted image, in window pixels.
+    pub width: u32,
+    /// Height of the blitted image, in window pixels.
+    pub height: u32,
+}
+
+/// Fit a `buffer_w * buffer_h` image inside a `win_w * win_h` window while
+/// preserving its aspect ratio.
+///
+/// The image is scaled by the largest factor that still fits in both
+/// dimensions and then centered, so non-matching window shapes get
+/// letterbox (top/bottom) or pillarbox (left/right) bars rather than a
+/// stretched, non-square-pixel picture.
+pub fn fit_to_window(buffer_w: u32, buffer_h: u32, win_w: u32, win_h: u32) -> ScaledRegion {
+    if buffer_w == 0 || buffer_h == 0 {
+        return ScaledRegion { x: 0, y: 0, width: win_w, height: win_h };
+    }
+
+    let scale = (win_w as f32 / buffer_w as f32).min(win_h as f32 / buffer_h as f32);
+    let width = (buffer_w as f32 * scale).round() as u32;
+    let height = (buffer_h as f32 * scale).round() as u32;
+    let x = win_w.saturating_sub(width) / 2;
+    let y = win_h.saturating_sub(height) / 2;
+
+    ScaledRegion { x, y, width, height }
+}